@@ -1,53 +1,379 @@
+use std::collections::HashSet;
+
 use proc_macro::TokenStream;
 use proc_macro2::{Span, TokenStream as TokenStream2};
 use quote::{quote, ToTokens, TokenStreamExt};
 use syn::{
-    self, braced,
+    self, braced, bracketed, parenthesized,
     parse::Parse,
     parse_macro_input, parse_quote,
     punctuated::{Pair, Punctuated},
     token::{self, Comma},
-    Field, FieldMutability, Fields, FnArg, Generics, Ident, ItemEnum, ItemFn, ItemTrait, LitStr,
-    Pat, Signature, Token, TraitItem, Type, Variant, Visibility,
+    Attribute, Expr, Field, FieldMutability, Fields, FnArg, GenericArgument, Generics, Ident,
+    ItemEnum, ItemFn, LitStr, Pat, PatIdent, PatType, PathArguments, ReturnType, Signature, Token,
+    Type, Variant, Visibility,
 };
 
 #[derive(Default)]
 struct InvokeBindingAttrs {
     cmd_prefix: Option<String>,
+    /// Name to give the generated ipc error enum; defaults to `IpcError`.
+    error: Option<String>,
+    /// Trait fns return a bare `T` instead of `Result<T, E>`, so there's no
+    /// app-level error to deserialize `invoke`'s `Err` into: it's reported
+    /// as `IpcError::Invoke` instead.
+    infallible: bool,
 }
 
 impl Parse for InvokeBindingAttrs {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
         let mut attrs: Self = Default::default();
         while !input.is_empty() {
-            let kv: KeyValuePair = input.parse()?;
-            if kv.key.as_str() == "cmd_prefix" {
-                attrs.cmd_prefix = Some(kv.value)
+            let key: Ident = input.parse()?;
+            if input.peek(Token![=]) {
+                let _: Token![=] = input.parse()?;
+                let value: LitStr = input.parse()?;
+                match key.to_string().as_str() {
+                    "cmd_prefix" => attrs.cmd_prefix = Some(value.value()),
+                    "error" => attrs.error = Some(value.value()),
+                    _ => {}
+                }
+            } else if key == "infallible" {
+                attrs.infallible = true;
+            }
+            if input.peek(Token![,]) {
+                let _: Token![,] = input.parse()?;
             }
         }
         Ok(attrs)
     }
 }
 
-struct KeyValuePair {
-    key: String,
-    value: String,
+/// A single argument in an `invoke_bindings` trait fn, optionally carrying a
+/// default value (`name: String = "world".into()`). The default is stripped
+/// before the argument is used to build the real fn `Signature`, but is kept
+/// around so the generated `macro_rules!` (see [`named_args_macro`]) can
+/// substitute it in for an omitted argument.
+struct InvokeArg {
+    ident: Ident,
+    colon_token: Token![:],
+    ty: Type,
+    default: Option<Expr>,
+}
+
+impl Parse for InvokeArg {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let ident: Ident = input.parse()?;
+        let colon_token: Token![:] = input.parse()?;
+        let ty: Type = input.parse()?;
+        let default = if input.peek(Token![=]) {
+            let _: Token![=] = input.parse()?;
+            Some(input.parse()?)
+        } else {
+            None
+        };
+        Ok(Self {
+            ident,
+            colon_token,
+            ty,
+            default,
+        })
+    }
+}
+
+/// A `fn` declaration inside an `invoke_bindings` trait body, parsed by hand
+/// (rather than via `syn::TraitItemFn`) so arguments may carry a `= <expr>`
+/// default, which isn't valid syntax for a real trait method.
+struct InvokeFn {
+    attrs: Vec<Attribute>,
+    asyncness: Option<Token![async]>,
+    fn_token: Token![fn],
+    ident: Ident,
+    generics: Generics,
+    inputs: Punctuated<InvokeArg, Comma>,
+    output: ReturnType,
 }
 
-impl Parse for KeyValuePair {
+impl Parse for InvokeFn {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
-        let key: Ident = input.parse()?;
-        let _: Token![=] = input.parse()?;
-        let value: LitStr = input.parse()?;
+        let attrs = input.call(Attribute::parse_outer)?;
+        let asyncness: Option<Token![async]> = input.parse()?;
+        let fn_token: Token![fn] = input.parse()?;
+        let ident: Ident = input.parse()?;
+        let mut generics: Generics = input.parse()?;
+        let content;
+        let _paren_token = parenthesized!(content in input);
+        let inputs = content.parse_terminated(InvokeArg::parse, Token![,])?;
+        let output: ReturnType = input.parse()?;
+        generics.where_clause = input.parse()?;
+        let _: Token![;] = input.parse()?;
         Ok(Self {
-            key: key.to_string(),
-            value: value.value(),
+            attrs,
+            asyncness,
+            fn_token,
+            ident,
+            generics,
+            inputs,
+            output,
         })
     }
 }
 
+/// `pub trait Commands { ... }`, parsed by hand to allow default-valued
+/// arguments in fn declarations (see [`InvokeArg`]).
+struct InvokeTrait {
+    attrs: Vec<Attribute>,
+    vis: Visibility,
+    trait_token: Token![trait],
+    ident: Ident,
+    generics: Generics,
+    items: Vec<InvokeFn>,
+}
+
+impl Parse for InvokeTrait {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let attrs = input.call(Attribute::parse_outer)?;
+        let vis: Visibility = input.parse()?;
+        let trait_token: Token![trait] = input.parse()?;
+        let ident: Ident = input.parse()?;
+        let mut generics: Generics = input.parse()?;
+        generics.where_clause = input.parse()?;
+        let content;
+        let _brace_token = braced!(content in input);
+        let mut items = Vec::new();
+        while !content.is_empty() {
+            items.push(content.parse()?);
+        }
+        Ok(Self {
+            attrs,
+            vis,
+            trait_token,
+            ident,
+            generics,
+            items,
+        })
+    }
+}
+
+/// Combines two sets of `Generics` (e.g. a trait's and one of its fn's) into
+/// one, concatenating params and merging `where` predicates, so a
+/// free-standing fn emitted outside of the trait can still name every type
+/// parameter it needs.
+fn merge_generics(a: &Generics, b: &Generics) -> Generics {
+    let mut merged = a.clone();
+    merged.params.extend(b.params.clone());
+    match (&mut merged.where_clause, &b.where_clause) {
+        (Some(merged_where), Some(b_where)) => {
+            merged_where.predicates.extend(b_where.predicates.clone())
+        }
+        (merged_where @ None, Some(b_where)) => *merged_where = Some(b_where.clone()),
+        _ => {}
+    }
+    merged
+}
+
+/// Collects every `Ident` appearing anywhere in `tokens`, recursing into
+/// groups, so callers can tell whether a generic parameter's name shows up
+/// inside some piece of syntax without matching on its shape.
+fn collect_idents(tokens: TokenStream2, idents: &mut HashSet<String>) {
+    for tt in tokens {
+        match tt {
+            proc_macro2::TokenTree::Ident(ident) => {
+                idents.insert(ident.to_string());
+            }
+            proc_macro2::TokenTree::Group(group) => collect_idents(group.stream(), idents),
+            _ => {}
+        }
+    }
+}
+
+/// Narrows `generics` down to the params (and the where-predicates that
+/// solely mention them) actually referenced by `types`, dropping the rest.
+///
+/// A free fn can carry unused generic params, but a local struct can't
+/// (E0392), so the `Args` struct emitted per command must only declare the
+/// subset of the fn's merged generics its own fields use.
+fn generics_used_in<'a>(generics: &Generics, types: impl Iterator<Item = &'a Type>) -> Generics {
+    let mut used = HashSet::new();
+    for ty in types {
+        collect_idents(quote! { #ty }, &mut used);
+    }
+
+    let param_name = |param: &syn::GenericParam| match param {
+        syn::GenericParam::Type(t) => t.ident.to_string(),
+        syn::GenericParam::Lifetime(l) => l.lifetime.ident.to_string(),
+        syn::GenericParam::Const(c) => c.ident.to_string(),
+    };
+    let all_params: HashSet<String> = generics.params.iter().map(param_name).collect();
+
+    let mut narrowed = generics.clone();
+    narrowed.params = Punctuated::from_iter(
+        narrowed.params.iter().cloned().filter(|param| used.contains(&param_name(param))),
+    );
+    let kept: HashSet<String> = narrowed.params.iter().map(param_name).collect();
+    if let Some(where_clause) = narrowed.where_clause.as_mut() {
+        where_clause.predicates = Punctuated::from_iter(
+            where_clause
+                .predicates
+                .iter()
+                .cloned()
+                .filter(|predicate| {
+                    let mut predicate_idents = HashSet::new();
+                    collect_idents(quote! { #predicate }, &mut predicate_idents);
+                    let mut mentioned = predicate_idents.intersection(&all_params).peekable();
+                    mentioned.peek().is_some() && mentioned.all(|name| kept.contains(name))
+                }),
+        );
+    }
+    narrowed
+}
+
+/// If `output` is `-> Result<T, E>`, returns `E`.
+fn result_error_type(output: &ReturnType) -> Option<&Type> {
+    let ReturnType::Type(_, ty) = output else {
+        return None;
+    };
+    let Type::Path(type_path) = ty.as_ref() else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+    if segment.ident != "Result" {
+        return None;
+    }
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    match args.args.iter().nth(1)? {
+        GenericArgument::Type(e) => Some(e),
+        _ => None,
+    }
+}
+
+/// Builds a `macro_rules!` named after `fn_ident` that accepts a
+/// comma-separated mix of positional exprs and `ident = expr` named pairs,
+/// reorders them into the canonical parameter order recorded in `inputs`,
+/// substitutes each parameter's default expr (if any) for an omitted
+/// argument, and calls the generated `fn_ident` with the result.
+///
+/// Named arguments are collected into a generated `__Args` struct of
+/// `Option<T>` fields: an unknown argument name is rejected with a
+/// `compile_error!`, a duplicate binding (the same field supplied twice,
+/// whether positionally or by name) is rejected by the struct literal itself,
+/// and an argument with no default that's simply never supplied is caught by
+/// a per-argument membership check emitted against the collected field-init
+/// tokens, so it's a `compile_error!` at macro-expansion time rather than a
+/// panic the first time the call site happens to omit it at runtime.
+fn named_args_macro(fn_ident: &Ident, inputs: &Punctuated<InvokeArg, Comma>) -> TokenStream2 {
+    let field_decls = inputs.iter().map(|arg| {
+        let ident = &arg.ident;
+        let ty = &arg.ty;
+        quote! { #ident: ::core::option::Option<#ty> }
+    });
+    let none_fields = inputs.iter().map(|arg| {
+        let ident = &arg.ident;
+        quote! { #ident: ::core::option::Option::None }
+    });
+    let resolved_args = inputs.iter().map(|arg| {
+        let ident = &arg.ident;
+        match &arg.default {
+            Some(default) => quote! { __args.#ident.unwrap_or_else(|| #default) },
+            // the `__<fn>_require_<arg>` checks below already fail the build with a
+            // `compile_error!` if this field was never supplied, so this
+            // fallback is never actually reached
+            None => quote! {
+                __args.#ident.unwrap_or_else(|| unreachable!(
+                    concat!("missing required argument `", stringify!(#ident), "` for `", stringify!(#fn_ident), "!`")
+                ))
+            },
+        }
+    });
+    let param_idents: Vec<&Ident> = inputs.iter().map(|arg| &arg.ident).collect();
+    let named_arms = inputs.iter().map(|arg| {
+        let ident = &arg.ident;
+        quote! {
+            (@munch [$($rem:ident)*] [$($coll:tt)*] #ident = $val:expr $(, $($rest:tt)*)?) => {
+                #fn_ident!(@munch [$($rem)*] [$($coll)* #ident: ::core::option::Option::Some($val),] $($($rest)*)?)
+            };
+        }
+    });
+
+    // for each argument with no default, a tiny recursive muncher that walks
+    // the collected `ident: Option::Some(val),` tokens looking for that
+    // specific ident, emitting a `compile_error!` if it's absent; this turns
+    // "caller omitted a required argument" into an expansion-time error
+    // instead of a `None.unwrap()` panic at call time. Declared as a
+    // sibling item to `#fn_ident`'s own `macro_rules!` (not nested inside
+    // one of its arms) since a `$val`/`$rest` bound by a nested definition
+    // would otherwise be parsed against the enclosing arm's own `$coll`/
+    // `$rem` repetition.
+    let require_checkers = inputs.iter().filter(|arg| arg.default.is_none()).map(|arg| {
+        let ident = &arg.ident;
+        let checker = Ident::new(&format!("__{fn_ident}_require_{ident}"), Span::call_site());
+        quote! {
+            #[allow(unused_macros)]
+            macro_rules! #checker {
+                (#ident : $val:expr , $($rest:tt)*) => {};
+                ($other:ident : $val:expr , $($rest:tt)*) => { #checker!($($rest)*) };
+                () => {
+                    compile_error!(concat!("missing required argument `", stringify!(#ident), "` for `", stringify!(#fn_ident), "!`"))
+                };
+            }
+        }
+    });
+    let require_checks = inputs.iter().filter(|arg| arg.default.is_none()).map(|arg| {
+        let ident = &arg.ident;
+        let checker = Ident::new(&format!("__{fn_ident}_require_{ident}"), Span::call_site());
+        quote! { #checker!($($coll)*); }
+    });
+
+    quote! {
+        #(#require_checkers)*
+
+        #[allow(unused_macros)]
+        macro_rules! #fn_ident {
+            (@munch [$($rem:ident)*] [$($coll:tt)*]) => {
+                {
+                    #(#require_checks)*
+                    struct __Args { #(#field_decls),* }
+                    let __args = __Args { $($coll)* ..__Args { #(#none_fields),* } };
+                    #fn_ident( #(#resolved_args),* )
+                }
+            };
+            #(#named_arms)*
+            (@munch [$($rem:ident)*] [$($coll:tt)*] $unknown:ident = $val:expr $(, $($rest:tt)*)?) => {
+                compile_error!(concat!("unknown argument `", stringify!($unknown), "` for `", stringify!(#fn_ident), "!`"))
+            };
+            (@munch [] [$($coll:tt)*] $val:expr $(, $($rest:tt)*)?) => {
+                compile_error!(concat!("too many positional arguments for `", stringify!(#fn_ident), "!`"))
+            };
+            (@munch [$head:ident $($rem:ident)*] [$($coll:tt)*] $val:expr $(, $($rest:tt)*)?) => {
+                #fn_ident!(@munch [$($rem)*] [$($coll)* $head: ::core::option::Option::Some($val),] $($($rest)*)?)
+            };
+            () => {
+                #fn_ident!(@munch [#(#param_idents)*] [])
+            };
+            ($($args:tt)*) => {
+                #fn_ident!(@munch [#(#param_idents)*] [] $($args)*)
+            };
+        }
+    }
+}
+
 /// Apply this to a trait, and generate an implementation for it's fns in the
-/// same scope that call `invoke` using the fn name as the command
+/// same scope that call `invoke` using the fn name as the command.
+///
+/// Arguments may carry a default value, in which case the generated binding
+/// can also be called through a `macro_rules!` of the same name using named
+/// and/or positional arguments, e.g. `hello!(name = "world".into())` or
+/// `hello!()` if every argument has a default.
+///
+/// Serializing the args and deserializing `invoke`'s result can themselves
+/// fail, independently of whatever the command reports; those failures are
+/// surfaced as a generated `IpcError` (override the name with
+/// `#[invoke_bindings(error = "MyError")]`) rather than panicking. Each
+/// trait fn must return `Result<T, E>` with `E: From<IpcError>`, unless it's
+/// marked `#[invoke_bindings(infallible)]`, in which case it may return a
+/// bare `T` and the binding itself returns `Result<T, IpcError>`.
 ///
 /// # Examples
 ///
@@ -55,74 +381,188 @@ impl Parse for KeyValuePair {
 /// #[allow(async_fn_in_trait)]
 /// #[tauri_bindgen_rs_macros::invoke_bindings]
 /// pub trait Commands {
-///     async hello(name: String) -> Result<String, String>;
+///     // `Error` is an app error type that is `Deserialize`, `Display` and `From<IpcError>`
+///     async fn hello(name: String = "world".into(), count: u32 = 1) -> Result<String, Error>;
 /// }
 ///
 /// async fn hello_world() -> Result<String, String> {
-///     hello("world".into())
+///     hello!(name = "world".into()).await.map_err(|err| err.to_string())
 /// }
 /// ```
 #[proc_macro_attribute]
 pub fn invoke_bindings(attrs: TokenStream, tokens: TokenStream) -> TokenStream {
     let attrs = parse_macro_input!(attrs as InvokeBindingAttrs);
-    let trait_item = parse_macro_input!(tokens as ItemTrait);
-    let fn_items = trait_item.items.iter().fold(Vec::new(), |mut m, item| {
-        if let TraitItem::Fn(fn_item) = item {
-            let fields: Punctuated<Field, Token![,]> =
-                Punctuated::from_iter(fn_item.sig.inputs.iter().fold(Vec::new(), |mut m, arg| {
-                    let pt = match arg {
-                        FnArg::Typed(pt) => pt,
-                        FnArg::Receiver(_) => {
-                            panic!("receiver arguments not supported");
-                        }
-                    };
-                    let ident = match pt.pat.as_ref() {
-                        Pat::Ident(pi) => Some(pi.ident.clone()),
-                        _ => panic!("argument not supported"),
-                    };
-                    let colon_token = Some(pt.colon_token);
-                    let ty = pt.ty.as_ref().clone();
-                    m.push(Field {
-                        attrs: Vec::new(),
-                        vis: Visibility::Inherited,
-                        mutability: FieldMutability::None,
-                        ident,
-                        colon_token,
-                        ty,
-                    });
-                    m
-                }));
-            let field_names: Punctuated<Ident, Token![,]> =
-                Punctuated::from_iter(fields.iter().map(|field| field.ident.clone().unwrap()));
-            let fn_name = fn_item.sig.ident.to_string();
-            let fn_name = attrs
-                .cmd_prefix
-                .clone()
-                .map_or(fn_name.clone(), |prefix| prefix + fn_name.as_str());
-            m.push(ItemFn {
+    let invoke_trait = parse_macro_input!(tokens as InvokeTrait);
+
+    let InvokeTrait {
+        attrs: trait_attrs,
+        vis,
+        trait_token,
+        ident: trait_ident,
+        generics: trait_generics,
+        items,
+    } = invoke_trait;
+
+    let (trait_decl_generics, _, trait_where_clause) = trait_generics.split_for_impl();
+
+    let error_ident = Ident::new(
+        attrs.error.clone().unwrap_or_else(|| "IpcError".to_string()).as_str(),
+        Span::call_site(),
+    );
+
+    let mut trait_items = Vec::new();
+    let mut fn_items = Vec::new();
+    let mut named_arg_macros = Vec::new();
+
+    for item in items.iter() {
+        let InvokeFn {
+            attrs: fn_attrs,
+            asyncness,
+            fn_token,
+            ident: fn_ident,
+            generics: fn_generics,
+            inputs,
+            output,
+        } = item;
+
+        let fields: Punctuated<Field, Token![,]> = Punctuated::from_iter(inputs.iter().map(|arg| Field {
+            attrs: Vec::new(),
+            vis: Visibility::Inherited,
+            mutability: FieldMutability::None,
+            ident: Some(arg.ident.clone()),
+            colon_token: Some(arg.colon_token),
+            ty: arg.ty.clone(),
+        }));
+        let field_names: Punctuated<Ident, Token![,]> =
+            Punctuated::from_iter(fields.iter().map(|field| field.ident.clone().unwrap()));
+
+        let sig_inputs: Punctuated<FnArg, Comma> = Punctuated::from_iter(inputs.iter().map(|arg| {
+            FnArg::Typed(PatType {
                 attrs: Vec::new(),
-                vis: trait_item.vis.clone(),
-                sig: fn_item.sig.clone(),
-                block: parse_quote!({
-                    #[derive(::serde::Serialize)]
-                    #[serde(rename_all = "camelCase")]
-                    struct Args {
-                        #fields
-                    }
-                    let args = Args { #field_names };
-                    let args: JsValue = ::serde_wasm_bindgen::to_value(&args).unwrap();
-                    match invoke(#fn_name, args).await {
-                        Ok(value) => Ok(::serde_wasm_bindgen::from_value(value).unwrap()),
-                        Err(err) => Err(::serde_wasm_bindgen::from_value(err).unwrap()),
-                    }
-                }),
+                pat: Box::new(Pat::Ident(PatIdent {
+                    attrs: Vec::new(),
+                    by_ref: None,
+                    mutability: None,
+                    ident: arg.ident.clone(),
+                    subpat: None,
+                })),
+                colon_token: arg.colon_token,
+                ty: Box::new(arg.ty.clone()),
+            })
+        }));
+
+        let trait_sig = Signature {
+            constness: None,
+            asyncness: *asyncness,
+            unsafety: None,
+            abi: None,
+            fn_token: *fn_token,
+            generics: fn_generics.clone(),
+            ident: fn_ident.clone(),
+            paren_token: token::Paren::default(),
+            inputs: sig_inputs.clone(),
+            variadic: None,
+            output: output.clone(),
+        };
+
+        trait_items.push(quote! { #(#fn_attrs)* #trait_sig; });
+
+        // the emitted async fn lives outside of the trait, so it needs to
+        // name every type parameter it uses, not just the ones it declares
+        // itself
+        let mut fn_generics = merge_generics(&trait_generics, fn_generics);
+
+        let fn_name = fn_ident.to_string();
+        let fn_name = attrs
+            .cmd_prefix
+            .clone()
+            .map_or(fn_name.clone(), |prefix| prefix + fn_name.as_str());
+
+        // `Args` is a local item inside the emitted fn's body, so unlike the
+        // fn itself it can't carry a generic param its fields don't use
+        // (E0392); narrow the fn's merged generics down to the ones that
+        // actually appear in an argument's type.
+        let args_generics = generics_used_in(&fn_generics, inputs.iter().map(|arg| &arg.ty));
+        let (args_impl_generics, _, args_where_clause) = args_generics.split_for_impl();
+
+        // serializing the args and deserializing either half of `invoke`'s
+        // result can themselves fail, independently of whatever the command
+        // itself reports
+        let args_block = quote! {
+            #[derive(::serde::Serialize)]
+            #[serde(rename_all = "camelCase")]
+            struct Args #args_impl_generics #args_where_clause {
+                #fields
+            }
+            // struct-literal field types drive inference, so `Args` needs no turbofish here
+            let args = Args { #field_names };
+            let args: JsValue = ::serde_wasm_bindgen::to_value(&args).map_err(#error_ident::Serialize)?;
+        };
+
+        let (fn_output, invoke_match) = if attrs.infallible {
+            // no app-level error to deserialize `Err` into, so it's surfaced
+            // as-is rather than attempted against a type that doesn't exist
+            let output_ty: Type = match output {
+                ReturnType::Type(_, ty) => (**ty).clone(),
+                ReturnType::Default => parse_quote!(()),
+            };
+            let fn_output: ReturnType = parse_quote!(-> ::core::result::Result<#output_ty, #error_ident>);
+            let invoke_match = quote! {
+                match invoke(#fn_name, args).await {
+                    Ok(value) => Ok(::serde_wasm_bindgen::from_value(value).map_err(#error_ident::Deserialize)?),
+                    Err(err) => Err(#error_ident::Invoke(err)),
+                }
+            };
+            (fn_output, invoke_match)
+        } else {
+            let e_ty = result_error_type(output).unwrap_or_else(|| {
+                panic!(
+                    "#[invoke_bindings] fn `{fn_ident}` must return `Result<T, E>` (or use the `infallible` attribute)"
+                )
             });
-        }
-        m
-    });
+            fn_generics
+                .make_where_clause()
+                .predicates
+                .push(parse_quote!(#e_ty: ::core::convert::From<#error_ident>));
+            let invoke_match = quote! {
+                match invoke(#fn_name, args).await {
+                    Ok(value) => Ok(::serde_wasm_bindgen::from_value(value).map_err(#error_ident::Deserialize)?),
+                    Err(err) => Err(::serde_wasm_bindgen::from_value(err).map_err(#error_ident::Deserialize)?),
+                }
+            };
+            (output.clone(), invoke_match)
+        };
+
+        let sig = Signature {
+            generics: fn_generics,
+            inputs: sig_inputs,
+            output: fn_output,
+            ..trait_sig
+        };
+
+        fn_items.push(ItemFn {
+            attrs: fn_attrs.clone(),
+            vis: vis.clone(),
+            sig,
+            block: parse_quote!({
+                #args_block
+                #invoke_match
+            }),
+        });
+
+        named_arg_macros.push(named_args_macro(fn_ident, inputs));
+    }
+
     let fn_items = ItemList { list: fn_items };
     let ret = quote! {
-        #trait_item
+        // `async fn` in a trait is warn-by-default, which `-D warnings`
+        // turns into a hard error; silence it unconditionally rather than
+        // relying on the user to remember their own `#[allow(...)]`
+        #[allow(async_fn_in_trait)]
+        #(#trait_attrs)*
+        #vis #trait_token #trait_ident #trait_decl_generics #trait_where_clause {
+            #(#trait_items)*
+        }
 
         use wasm_bindgen::prelude::*;
 
@@ -132,7 +572,31 @@ pub fn invoke_bindings(attrs: TokenStream, tokens: TokenStream) -> TokenStream {
             async fn invoke(cmd: &str, args: JsValue) -> Result<JsValue, JsValue>;
         }
 
+        /// Errors that can occur while marshalling an `invoke_bindings` call
+        /// across the IPC boundary, as opposed to an error the command
+        /// itself reports.
+        #[derive(Debug)]
+        #vis enum #error_ident {
+            Serialize(::serde_wasm_bindgen::Error),
+            Deserialize(::serde_wasm_bindgen::Error),
+            Invoke(JsValue),
+        }
+
+        impl ::std::fmt::Display for #error_ident {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                match self {
+                    #error_ident::Serialize(err) => write!(f, "failed to serialize ipc args: {err}"),
+                    #error_ident::Deserialize(err) => write!(f, "failed to deserialize ipc payload: {err}"),
+                    #error_ident::Invoke(err) => write!(f, "invoke failed: {err:?}"),
+                }
+            }
+        }
+
+        impl ::std::error::Error for #error_ident {}
+
         #fn_items
+
+        #(#named_arg_macros)*
     };
 
     TokenStream::from(ret)
@@ -154,10 +618,13 @@ pub fn invoke_bindings(attrs: TokenStream, tokens: TokenStream) -> TokenStream {
 ///
 /// // ...
 ///
-/// let listener = EventBinding::SomethingHappened.listen(|event: Event| {
-///     // ...
+/// let listener = EventBinding::SomethingHappened.listen(|event: Result<Event, EventError>| {
+///     // a malformed payload surfaces as `Err` rather than panicking
 /// }).await;
 /// drop(listener); // unlisten
+///
+/// // emit an event from the frontend, e.g. for a backend `listen` handler
+/// EventBinding::SomeoneSaidHello.emit(Event::SomeoneSaidHello("hi".into())).await?;
 /// ```
 #[proc_macro_derive(Events)]
 pub fn derive_event(tokens: TokenStream) -> TokenStream {
@@ -174,10 +641,11 @@ pub fn derive_event(tokens: TokenStream) -> TokenStream {
 
     fn derive_impl_display(
         vis: Visibility,
-        _generics: Generics, // TODO: support generics
+        generics: Generics,
         ident: Ident,
         variants: Punctuated<Variant, Comma>,
     ) -> TokenStream2 {
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
         let match_arms: Punctuated<TokenStream2, Comma> = variants
             .iter()
             .map(|v| -> TokenStream2 {
@@ -213,7 +681,7 @@ pub fn derive_event(tokens: TokenStream) -> TokenStream {
             })
             .collect();
         let ret = quote! {
-            impl #ident {
+            impl #impl_generics #ident #ty_generics #where_clause {
                 #vis fn event_name(&self) -> &'static str {
                     match self {
                         #match_arms
@@ -225,7 +693,7 @@ pub fn derive_event(tokens: TokenStream) -> TokenStream {
     }
 
     fn derive_event_binding(
-        _generics: Generics, // TODO: support generics
+        generics: Generics,
         ident: Ident,
         variants: Punctuated<Variant, Comma>,
     ) -> TokenStream2 {
@@ -244,24 +712,56 @@ pub fn derive_event(tokens: TokenStream) -> TokenStream {
                 .into()
             })
             .collect();
+
+        // `EventBinding` itself doesn't hold a payload, so it stays
+        // non-generic; the original enum's type params (and their bounds)
+        // are instead reintroduced on `listen` and `emit`, the only places
+        // they're needed.
+        let (_, ty_generics, _) = generics.split_for_impl();
+        let listen_params: Vec<_> = generics.params.iter().collect();
+        let listen_predicates = generics
+            .where_clause
+            .as_ref()
+            .map(|where_clause| where_clause.predicates.clone())
+            .unwrap_or_default();
+
         let ret = quote! {
             pub enum #event_binding_ident {
                 #variant_names
             }
 
             impl #event_binding_ident {
-                pub async fn listen<F>(&self, handler: F) -> Result<EventListener, JsValue>
+                /// `handler` is called with `Err(EventError::Deserialize(_))`
+                /// instead of panicking if a received event doesn't match
+                /// the event enum's shape.
+                pub async fn listen<#(#listen_params,)* F>(&self, handler: F) -> Result<EventListener, JsValue>
                 where
-                    F: Fn(#ident) + 'static,
+                    F: Fn(Result<#ident #ty_generics, EventError>) + 'static,
+                    #listen_predicates
                 {
                     let event_name = self.as_str();
                     EventListener::new(event_name, move |event| {
-                        let event: TauriEvent<#ident> = ::serde_wasm_bindgen::from_value(event).unwrap();
-                        handler(event.payload);
+                        let event: Result<TauriEvent<#ident #ty_generics>, EventError> =
+                            ::serde_wasm_bindgen::from_value(event).map_err(EventError::Deserialize);
+                        handler(event.map(|event| event.payload));
                     })
                     .await
                 }
 
+                /// Emits `value` as an event on the frontend, so a backend
+                /// `listen` handler receives it symmetrically. The event
+                /// name is derived from `value`'s variant, not from the
+                /// binding `self` was called on.
+                pub async fn emit<#(#listen_params,)*>(&self, value: #ident #ty_generics) -> Result<(), EventError>
+                where
+                    #ident #ty_generics: ::serde::Serialize,
+                    #listen_predicates
+                {
+                    let event_name = value.event_name();
+                    let payload: JsValue = ::serde_wasm_bindgen::to_value(&value).map_err(EventError::Serialize)?;
+                    emit(event_name, payload).await.map_err(EventError::Emit)
+                }
+
                 fn as_str(&self) -> &str {
                     match self {
                         #variant_to_str_match_arms
@@ -284,6 +784,9 @@ pub fn derive_event(tokens: TokenStream) -> TokenStream {
                     event_name: &str,
                     handler: &Closure<dyn FnMut(JsValue)>,
                 ) -> Result<JsValue, JsValue>;
+
+                #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "event"], catch)]
+                async fn emit(event_name: &str, payload: JsValue) -> Result<(), JsValue>;
             }
 
             #vis struct EventListener {
@@ -323,6 +826,27 @@ pub fn derive_event(tokens: TokenStream) -> TokenStream {
             struct TauriEvent<T> {
                 pub payload: T,
             }
+
+            /// Errors that can occur while marshalling an event across the
+            /// IPC boundary.
+            #[derive(Debug)]
+            #vis enum EventError {
+                Serialize(::serde_wasm_bindgen::Error),
+                Deserialize(::serde_wasm_bindgen::Error),
+                Emit(JsValue),
+            }
+
+            impl ::std::fmt::Display for EventError {
+                fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                    match self {
+                        EventError::Serialize(err) => write!(f, "failed to serialize event payload: {err}"),
+                        EventError::Deserialize(err) => write!(f, "failed to deserialize event payload: {err}"),
+                        EventError::Emit(err) => write!(f, "emit failed: {err:?}"),
+                    }
+                }
+            }
+
+            impl ::std::error::Error for EventError {}
         }
     }
 
@@ -347,6 +871,11 @@ pub fn derive_event(tokens: TokenStream) -> TokenStream {
 
 struct ImplTrait {
     trait_ident: Ident,
+    /// Extra type idents (matched against a parameter type's last path
+    /// segment) to strip from the ghost impl, for injected state that isn't
+    /// under the `tauri::` path, e.g. an aliased `use tauri::State as St;`
+    /// or a custom managed-state wrapper.
+    ignore: HashSet<Ident>,
     fns: ItemList<ItemFn>,
 }
 
@@ -355,12 +884,34 @@ impl Parse for ImplTrait {
         let fns;
         let trait_ident = input.parse()?;
         let _: Token![,] = input.parse()?;
-        let _: token::Brace = braced!(fns in input);
+
+        let mut ignore = HashSet::new();
+        if input.peek(syn::Ident) {
+            let _: kw::ignore = input.parse()?;
+            let _: Token![=] = input.parse()?;
+            let content;
+            let _bracket_token = bracketed!(content in input);
+            ignore = content
+                .parse_terminated(Ident::parse, Token![,])?
+                .into_iter()
+                .collect();
+            let _: Token![,] = input.parse()?;
+        }
+
+        let _brace_token = braced!(fns in input);
         let fns = fns.parse()?;
-        Ok(ImplTrait { trait_ident, fns })
+        Ok(ImplTrait {
+            trait_ident,
+            ignore,
+            fns,
+        })
     }
 }
 
+mod kw {
+    syn::custom_keyword!(ignore);
+}
+
 struct ItemList<I: ToTokens> {
     list: Vec<I>,
 }
@@ -386,9 +937,21 @@ impl<I: ToTokens> ToTokens for ItemList<I> {
 
 /// Takes the name of a trait and an impl block, and emits a ghost struct that
 /// implements that trait using the provided fn signaturesâ€”stripping away any
-/// generics and arguments with `tauri` as the first path segment.
+/// arguments with `tauri` as the first path segment, plus any named in an
+/// `ignore = [...]` list (each fn's own generics are kept as-is on the ghost
+/// impl).
 ///
-/// TODO: accept a list of arguments to ignore vs relying on the `tauri::` prefix.
+/// Each fn in the impl block is also given a `#[tauri::command]` attribute,
+/// and a `__<Trait>_handler` fn is generated that expands to
+/// `tauri::generate_handler![...]` over every command in declaration order,
+/// so the whole surface can be wired up with
+/// `.invoke_handler(commands::__Commands_handler())` instead of hand-maintaining
+/// the list passed to `generate_handler!`.
+///
+/// Parameters whose type isn't under the `tauri::` path (an aliased
+/// `use tauri::State as St;`, or a custom managed-state wrapper like a
+/// database handle) can still be stripped by naming their type's last path
+/// segment in an `ignore = [...]` list, passed before the impl block.
 ///
 /// # Examples
 ///
@@ -402,22 +965,30 @@ impl<I: ToTokens> ToTokens for ItemList<I> {
 /// mod tauri {
 ///     struct State {}
 /// }
+/// struct Db;
 ///
-/// tauri_bindgen_rs_macros::impl_trait!(Commands, {
-///     // we'll also need a #[tauri::command] attribute here
-///     async foo(state: tauri::State, bar: String) -> Result<(), String> {
+/// tauri_bindgen_rs_macros::impl_trait!(Commands, ignore = [Db], {
+///     async fn foo(state: tauri::State, db: Db, bar: String) -> Result<(), String> {
 ///         Ok(())
 ///     }
 ///
-///     // we'll also need a #[tauri::command] attribute here
 ///     async bar(state: tauri::State, foo: String) -> Result<(), String> {
 ///         Ok(())
 ///     }
 /// });
+///
+/// tauri::Builder::default()
+///     .invoke_handler(__Commands_handler())
+///     // ...
+///     ;
 /// ```
 #[proc_macro]
 pub fn impl_trait(tokens: TokenStream) -> TokenStream {
-    let ImplTrait { trait_ident, fns } = parse_macro_input!(tokens as ImplTrait);
+    let ImplTrait {
+        trait_ident,
+        ignore,
+        fns,
+    } = parse_macro_input!(tokens as ImplTrait);
 
     let mut trait_fns = Vec::new();
 
@@ -435,7 +1006,10 @@ pub fn impl_trait(tokens: TokenStream) -> TokenStream {
         item
     }
 
-    fn filter_map_fn_inputs(inputs: Punctuated<FnArg, Comma>) -> Punctuated<FnArg, Comma> {
+    fn filter_map_fn_inputs(
+        inputs: Punctuated<FnArg, Comma>,
+        ignore: &HashSet<Ident>,
+    ) -> Punctuated<FnArg, Comma> {
         let tauri_ident = Ident::new("tauri", Span::call_site());
         Punctuated::from_iter(inputs.into_pairs().fold(Vec::new(), |mut m, item| {
             if let Some(tp) = match item.value() {
@@ -450,6 +1024,11 @@ pub fn impl_trait(tokens: TokenStream) -> TokenStream {
                         return m;
                     }
                 }
+                if let Some(s) = tp.path.segments.last() {
+                    if ignore.contains(&s.ident) {
+                        return m;
+                    }
+                }
             }
             m.push(map_fn_input(item));
             m
@@ -467,10 +1046,10 @@ pub fn impl_trait(tokens: TokenStream) -> TokenStream {
                 unsafety: None,
                 abi: None,
                 fn_token: sig.fn_token,
-                generics: Default::default(),
+                generics: sig.generics.clone(),
                 ident: sig.ident.clone(),
                 paren_token: sig.paren_token,
-                inputs: filter_map_fn_inputs(sig.inputs.clone()),
+                inputs: filter_map_fn_inputs(sig.inputs.clone(), &ignore),
                 variadic: None,
                 output: sig.output.clone(),
             },
@@ -481,6 +1060,27 @@ pub fn impl_trait(tokens: TokenStream) -> TokenStream {
     let struct_name = Ident::new(format!("__Impl{}", trait_ident).as_str(), Span::call_site());
     let trait_fns = ItemList { list: trait_fns };
 
+    // walk the provided impl fns, attaching `#[tauri::command]` to each and
+    // accumulating their idents (in declaration order) for `generate_handler!`
+    let mut command_idents = Vec::new();
+    let commands = ItemList {
+        list: fns
+            .list
+            .iter()
+            .map(|func| {
+                command_idents.push(func.sig.ident.clone());
+                let mut func = func.clone();
+                func.attrs.push(parse_quote!(#[tauri::command]));
+                func
+            })
+            .collect(),
+    };
+
+    let handler_ident = Ident::new(
+        format!("__{}_handler", trait_ident).as_str(),
+        Span::call_site(),
+    );
+
     let ret = quote! {
         struct #struct_name {}
 
@@ -488,7 +1088,12 @@ pub fn impl_trait(tokens: TokenStream) -> TokenStream {
             #trait_fns
         }
 
-        #fns
+        #commands
+
+        pub fn #handler_ident<R: ::tauri::Runtime>(
+        ) -> impl Fn(::tauri::ipc::Invoke<R>) -> bool + Send + Sync + 'static {
+            ::tauri::generate_handler![#(#command_idents),*]
+        }
     };
 
     TokenStream::from(ret)